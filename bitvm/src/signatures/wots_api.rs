@@ -20,8 +20,10 @@ macro_rules! impl_wots {
     ($mod_name:ident, $MSG_LEN:expr) => {
         pub mod $mod_name {
             use super::*;
-            use bitcoin::hex::FromHex;
+            use bitcoin::hex::{DisplayHex, FromHex};
             use bitcoin_script::script;
+            use hmac::{Hmac, Mac};
+            use sha2::Sha512;
 
             /// Message length in bytes.
             pub const MSG_LEN: u32 = $MSG_LEN;
@@ -112,6 +114,44 @@ macro_rules! impl_wots {
                 pubkey_vec.try_into().unwrap()
             }
 
+            /// Derives the `index`-th per-key secret from a master `seed`, BIP32-style: `I =
+            /// HMAC-SHA512(seed, "WOTS" || mod_name || index)`. The module name is mixed in as a
+            /// domain separator so that e.g. `wots_hash` and `wots256` never derive the same
+            /// secret for the same `(seed, index)`, even though both reduce to the same
+            /// `derive_secret` body. The left 32 bytes become the key material (hex-encoded below
+            /// to reuse the existing string-keyed API); the right 32 bytes are a chain code a
+            /// caller can feed back in as `seed` to derive a further level of an optional
+            /// derivation path.
+            pub fn derive_secret(seed: &[u8], index: u64) -> ([u8; 32], [u8; 32]) {
+                let mut mac = Hmac::<Sha512>::new_from_slice(seed)
+                    .expect("HMAC-SHA512 accepts a key of any length");
+                mac.update(b"WOTS");
+                mac.update(stringify!($mod_name).as_bytes());
+                mac.update(&index.to_le_bytes());
+                let i = mac.finalize().into_bytes();
+
+                let mut secret = [0u8; 32];
+                let mut chain_code = [0u8; 32];
+                secret.copy_from_slice(&i[..32]);
+                chain_code.copy_from_slice(&i[32..]);
+                (secret, chain_code)
+            }
+
+            /// Generates the `index`-th WOTS public key deterministically derived from a master
+            /// `seed`. A thin wrapper over [`generate_public_key`] that reconstructs the hex
+            /// secret on every call, so a whole tree of one-time keys reduces to one seed.
+            pub fn generate_public_key_from_seed(seed: &[u8], index: u64) -> PublicKey {
+                let (secret, _chain_code) = derive_secret(seed, index);
+                generate_public_key(&secret.to_lower_hex_string())
+            }
+
+            /// Signs `message` under the `index`-th key deterministically derived from a master
+            /// `seed`. A thin wrapper over [`get_signature`].
+            pub fn get_signature_from_seed(seed: &[u8], index: u64, message: &[u8]) -> Signature {
+                let (secret, _chain_code) = derive_secret(seed, index);
+                get_signature(&secret.to_lower_hex_string(), message)
+            }
+
             /// A sub-module for the compact signature variant.
             pub mod compact {
                 use super::*;
@@ -164,6 +204,14 @@ macro_rules! impl_wots {
                     assert_eq!(sigs.len(), N_DIGITS as usize);
                     raw_witness_to_signature(&sigs)
                 }
+
+                /// Signs `message` under the `index`-th key deterministically derived from a
+                /// master `seed`, using the compact signature variant. A thin wrapper over
+                /// [`get_signature`].
+                pub fn get_signature_from_seed(seed: &[u8], index: u64, message: &[u8]) -> Signature {
+                    let (secret, _chain_code) = super::derive_secret(seed, index);
+                    get_signature(&secret.to_lower_hex_string(), message)
+                }
             }
         }
     };